@@ -1,13 +1,15 @@
+use alloc::vec::Vec;
+
 use crate::sections::{
-    element_section::ElementSection, export_section::ExportSection,
+    custom_section::CustomSection, element_section::ElementSection, export_section::ExportSection,
     function_section::FunctionSection, global_section::GlobalSection,
-    import_section::ImportSection, memory_section::MemorySection, start_section::StartSection,
-    type_section::TypeSection,
+    import_section::ImportSection, memory_section::MemorySection, name_section::NameSection,
+    start_section::StartSection, type_section::TypeSection,
 };
 
 #[derive(Debug, Default, PartialEq)]
 pub struct Module {
-    pub custom_section: Option<()>,
+    pub custom_sections: Vec<CustomSection>,
     pub type_section: Option<TypeSection>,
     pub import_section: Option<ImportSection>,
     pub function_section: Option<FunctionSection>,
@@ -16,4 +18,5 @@ pub struct Module {
     pub export_section: Option<ExportSection>,
     pub start_section: Option<StartSection>,
     pub element_section: Option<ElementSection>,
+    pub name_section: Option<NameSection>,
 }
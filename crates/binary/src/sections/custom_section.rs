@@ -0,0 +1,10 @@
+use alloc::{boxed::Box, string::String};
+
+/// Custom section.
+///
+/// WebAssembly specification: https://webassembly.github.io/spec/core/binary/modules.html#custom-section
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomSection {
+    pub name: String,
+    pub bytes: Box<[u8]>,
+}
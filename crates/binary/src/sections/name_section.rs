@@ -0,0 +1,24 @@
+use alloc::{boxed::Box, string::String};
+
+/// Name section.
+///
+/// WebAssembly specification: https://webassembly.github.io/spec/core/appendix/custom.html#name-section
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NameSection {
+    pub module_name: Option<String>,
+    pub function_names: NameMap,
+}
+
+/// Name map.
+///
+/// WebAssembly specification: https://webassembly.github.io/spec/core/appendix/custom.html#name-maps
+pub type NameMap = Box<[NameAssoc]>;
+
+/// Name association.
+///
+/// WebAssembly specification: https://webassembly.github.io/spec/core/appendix/custom.html#name-maps
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameAssoc {
+    pub index: u32,
+    pub name: String,
+}
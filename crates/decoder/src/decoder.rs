@@ -1,6 +1,7 @@
 use crate::{Error, Result, SectionId};
 use binary::{
-    CodeSection, FunctionSection, Import, ImportDesc, ImportSection, Module, Type, TypeSection,
+    CodeSection, CustomSection, FunctionSection, Import, ImportDesc, ImportSection, Module,
+    NameAssoc, NameMap, NameSection, Type, TypeSection,
 };
 use std::io::{BufReader, Read};
 use types::{FuncType, ValueType};
@@ -33,9 +34,21 @@ impl<R: Read> Decoder<R> {
                 break result;
             }
 
-            let (id, _) = result.unwrap();
+            let (id, size) = result.unwrap();
 
             match id {
+                SectionId::Custom => {
+                    let custom_section = self.decode_custom_section(size)?;
+
+                    // Errors in custom sections must not invalidate the module, so name
+                    // subsections that fail to decode are ignored.
+                    if custom_section.name == "name" {
+                        let mut decoder = Decoder::new(&custom_section.bytes[..]);
+                        module.name_section = decoder.decode_name_section().ok();
+                    }
+
+                    module.custom_sections.push(custom_section);
+                }
                 SectionId::Type => {
                     module.type_section = Some(self.decode_type_section()?);
                 }
@@ -137,6 +150,79 @@ impl<R: Read> Decoder<R> {
         Ok(Box::from(type_indexes))
     }
 
+    fn decode_custom_section(&mut self, size: u32) -> Result<CustomSection> {
+        let payload = self.read_bytes(size)?;
+        let mut decoder = Decoder::new(&payload[..]);
+        let name = decoder.read_name()?;
+
+        let mut bytes = Vec::new();
+        decoder
+            .reader
+            .read_to_end(&mut bytes)
+            .map_err(|_| Error::UnexpectedEof)?;
+
+        Ok(CustomSection {
+            name,
+            bytes: Box::from(bytes),
+        })
+    }
+
+    /// Decodes the name section one subsection at a time. A subsection that fails to decode is
+    /// skipped, and an error is returned only if no subsection could be decoded.
+    fn decode_name_section(&mut self) -> Result<NameSection> {
+        let mut name_section = NameSection::default();
+        let mut decoded = false;
+        let mut error = None;
+
+        loop {
+            let id = match self.read_u8() {
+                Ok(id) => id,
+                Err(Error::UnexpectedEof) => break,
+                Err(err) => return Err(err),
+            };
+
+            // Without a valid size the subsection can't be skipped, so the rest is unreadable.
+            let payload = match self.read_size().and_then(|size| self.read_bytes(size)) {
+                Ok(payload) => payload,
+                Err(err) => {
+                    error = Some(err);
+                    break;
+                }
+            };
+            let mut decoder = Decoder::new(&payload[..]);
+
+            let result = match id {
+                0x00 => decoder
+                    .read_name()
+                    .map(|name| name_section.module_name = Some(name)),
+                0x01 => decoder
+                    .decode_name_map()
+                    .map(|names| name_section.function_names = names),
+                _ => Ok(()),
+            };
+
+            match result {
+                Ok(_) => decoded = true,
+                Err(err) => error = Some(err),
+            }
+        }
+
+        match error {
+            Some(err) if !decoded => Err(err),
+            _ => Ok(name_section),
+        }
+    }
+
+    fn decode_name_map(&mut self) -> Result<NameMap> {
+        let names = self.read_vec(|d| {
+            let index = d.read_size()?;
+            let name = d.read_name()?;
+            Ok(NameAssoc { index, name })
+        })?;
+
+        Ok(Box::from(names))
+    }
+
     fn decode_code_section(&mut self) -> Result<CodeSection> {}
 
     fn decode_section(&mut self) -> Result<(SectionId, u32)> {
@@ -170,6 +256,16 @@ impl<R: Read> Decoder<R> {
         }
     }
 
+    fn read_bytes(&mut self, size: u32) -> Result<Vec<u8>> {
+        let mut buf = vec![0; size as usize];
+        let result = self.reader.read_exact(&mut buf);
+
+        match result {
+            Ok(_) => Ok(buf),
+            Err(_) => Err(Error::UnexpectedEof),
+        }
+    }
+
     fn read_vec<T>(&mut self, parser: impl Fn(&mut Self) -> Result<T>) -> Result<Vec<T>> {
         let size = self.read_size()?;
         let mut items = Vec::with_capacity(size as usize);
@@ -187,7 +283,7 @@ impl<R: Read> Decoder<R> {
         let result = self.reader.read_exact(&mut buf);
 
         match result {
-            Ok(_) => Ok(String::from_utf8(buf).unwrap()),
+            Ok(_) => String::from_utf8(buf).map_err(|_| Error::InvalidUtf8),
             Err(_) => Err(Error::UnexpectedEof),
         }
     }
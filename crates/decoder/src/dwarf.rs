@@ -0,0 +1,172 @@
+use crate::{Error, Result};
+use binary::Module;
+use gimli::{
+    AttributeValue, ColumnType, DebugLine, DebugLineOffset, DebugLineStr, DebugStr, EndianSlice,
+    LineProgramHeader, LineRow, LittleEndian,
+};
+use std::fmt;
+
+type Reader<'a> = EndianSlice<'a, LittleEndian>;
+
+/// Address size of wasm32 code, used by DWARF versions whose line programs don't record it.
+const ADDRESS_SIZE: u8 = 4;
+
+/// Source location.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub file: String,
+    pub line: u64,
+    /// Column number, or `0` for the left edge of the line.
+    pub column: u64,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)?;
+
+        if self.column != 0 {
+            write!(f, ":{}", self.column)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Line table built from the DWARF line programs in the `.debug_line` custom section.
+///
+/// Addresses in WebAssembly DWARF are offsets from the start of the code section's contents.
+///
+/// DWARF specification: https://dwarfstd.org/doc/DWARF5.pdf#section.6.2
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineTable {
+    /// Rows sorted by address. `None` marks an address without source, such as the end of a
+    /// sequence.
+    rows: Vec<(u64, Option<Location>)>,
+}
+
+impl LineTable {
+    /// Builds a line table from the DWARF custom sections of `module`. A module without
+    /// `.debug_line` yields an empty table.
+    pub fn from_module(module: &Module) -> Result<LineTable> {
+        let section = |name: &str| {
+            module
+                .custom_sections
+                .iter()
+                .find(|section| section.name == name)
+                .map(|section| &section.bytes[..])
+                .unwrap_or(&[])
+        };
+
+        LineTable::parse(
+            section(".debug_line"),
+            section(".debug_line_str"),
+            section(".debug_str"),
+        )
+    }
+
+    /// Parses every line program in `debug_line`. String forms are resolved against
+    /// `debug_line_str` and `debug_str`.
+    pub fn parse(debug_line: &[u8], debug_line_str: &[u8], debug_str: &[u8]) -> Result<LineTable> {
+        let strings = Strings {
+            debug_line_str: DebugLineStr::new(debug_line_str, LittleEndian),
+            debug_str: DebugStr::new(debug_str, LittleEndian),
+        };
+        let programs = DebugLine::new(debug_line, LittleEndian);
+        let mut rows = Vec::new();
+        let mut offset = 0;
+
+        while offset < debug_line.len() {
+            let program = programs
+                .program(DebugLineOffset(offset), ADDRESS_SIZE, None, None)
+                .map_err(|_| Error::InvalidDwarf)?;
+            let header = program.header();
+            offset += header.format().initial_length_size() as usize + header.unit_length();
+
+            let mut program_rows = program.rows();
+            while let Some((header, row)) =
+                program_rows.next_row().map_err(|_| Error::InvalidDwarf)?
+            {
+                let location = if row.end_sequence() {
+                    None
+                } else {
+                    strings.location(header, row)?
+                };
+
+                rows.push((row.address(), location));
+            }
+        }
+
+        // End-of-sequence markers sort before a sequence starting at the same address.
+        rows.sort_by_key(|(address, location)| (*address, location.is_some()));
+
+        Ok(LineTable { rows })
+    }
+
+    /// Returns the source location of the instruction at `offset` in the code section.
+    pub fn lookup(&self, offset: u64) -> Option<&Location> {
+        let index = self.rows.partition_point(|(address, _)| *address <= offset);
+
+        match index {
+            0 => None,
+            _ => self.rows[index - 1].1.as_ref(),
+        }
+    }
+}
+
+struct Strings<'a> {
+    debug_line_str: DebugLineStr<Reader<'a>>,
+    debug_str: DebugStr<Reader<'a>>,
+}
+
+impl<'a> Strings<'a> {
+    fn location(
+        &self,
+        header: &LineProgramHeader<Reader<'a>>,
+        row: &LineRow,
+    ) -> Result<Option<Location>> {
+        let (Some(line), Some(file)) = (row.line(), row.file(header)) else {
+            return Ok(None);
+        };
+
+        let name = self.string(file.path_name())?;
+        let file = match file.directory(header) {
+            Some(directory) if !name.starts_with('/') => {
+                let directory = self.string(directory)?;
+                if directory.is_empty() {
+                    name
+                } else {
+                    format!("{}/{}", directory.trim_end_matches('/'), name)
+                }
+            }
+            _ => name,
+        };
+
+        let column = match row.column() {
+            ColumnType::LeftEdge => 0,
+            ColumnType::Column(column) => column.get(),
+        };
+
+        Ok(Some(Location {
+            file,
+            line: line.get(),
+            column,
+        }))
+    }
+
+    fn string(&self, value: AttributeValue<Reader<'a>>) -> Result<String> {
+        let bytes = match value {
+            AttributeValue::String(bytes) => bytes,
+            AttributeValue::DebugLineStrRef(offset) => self
+                .debug_line_str
+                .get_str(offset)
+                .map_err(|_| Error::InvalidDwarf)?,
+            AttributeValue::DebugStrRef(offset) => self
+                .debug_str
+                .get_str(offset)
+                .map_err(|_| Error::InvalidDwarf)?,
+            _ => return Err(Error::InvalidDwarf),
+        };
+
+        Ok(String::from_utf8_lossy(bytes.slice()).into_owned())
+    }
+}
@@ -1,7 +1,9 @@
 pub mod decoder;
+pub mod dwarf;
 pub mod result;
 pub mod section_id;
 
 pub use crate::decoder::*;
+pub use crate::dwarf::*;
 pub use crate::result::*;
 pub use crate::section_id::*;
@@ -8,10 +8,13 @@ pub enum Error {
     InvalidSectionSize,
 
     UnexpectedEof,
+    InvalidUtf8,
 
     InvalidTypeKind,
 
     InvalidImportDesc,
+
+    InvalidDwarf,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
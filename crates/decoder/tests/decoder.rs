@@ -1,7 +1,7 @@
 use binary::Type;
 use decoder::{Decoder, Error};
 use types::FuncType;
-use wabt::wat2wasm;
+use wabt::{wat2wasm, Wat2Wasm};
 
 #[test]
 fn test_should_returns_error_when_magic_header_is_invalid() {
@@ -45,6 +45,34 @@ fn test_should_decode_type_section() {
     );
 }
 
+#[test]
+fn test_should_decode_vector_and_reference_types_in_type_section() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x01, 0x07, // type section
+        0x01, 0x60, 0x03, 0x7b, 0x70, 0x6f, 0x00, // (func (param v128 funcref externref))
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result.type_section,
+        Some(
+            vec![Type::Func(FuncType {
+                params: Box::from([
+                    types::ValueType::V128,
+                    types::ValueType::Ref(types::RefType::FuncRef),
+                    types::ValueType::Ref(types::RefType::ExternRef),
+                ]),
+                results: Box::from([]),
+            })]
+            .into_boxed_slice()
+        )
+    );
+}
+
 #[test]
 fn test_should_decode_import_section() {
     let wasm = wat2wasm(
@@ -79,6 +107,167 @@ fn test_should_decode_import_section() {
     );
 }
 
+#[test]
+fn test_should_decode_name_section() {
+    let wasm = Wat2Wasm::new()
+        .write_debug_names(true)
+        .convert(
+            "
+        (module
+            (import \"env\" \"add\" (func $add (param i32 i32) (result i32)))
+            (import \"env\" \"addf\" (func $addf (param f32 f32) (result f32)))
+        )",
+        )
+        .expect("Failed to parse wat")
+        .as_ref()
+        .to_vec();
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result.name_section.map(|section| section.function_names),
+        Some(
+            vec![
+                binary::NameAssoc {
+                    index: 0,
+                    name: "add".to_string(),
+                },
+                binary::NameAssoc {
+                    index: 1,
+                    name: "addf".to_string(),
+                },
+            ]
+            .into_boxed_slice()
+        )
+    );
+}
+
+#[test]
+fn test_should_keep_custom_sections() {
+    let wasm = Wat2Wasm::new()
+        .write_debug_names(true)
+        .convert("(module (import \"env\" \"add\" (func $add)))")
+        .expect("Failed to parse wat")
+        .as_ref()
+        .to_vec();
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result
+            .custom_sections
+            .iter()
+            .map(|section| section.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["name"]
+    );
+}
+
+#[test]
+fn test_should_decode_module_name_from_name_section() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, 0x0e, // custom section
+        0x04, b'n', b'a', b'm', b'e', // name
+        0x00, 0x02, 0x01, b'm', // module name: "m"
+        0x01, 0x03, 0x01, 0x00, 0x00, // function names: 0 => ""
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result.name_section,
+        Some(binary::NameSection {
+            module_name: Some("m".to_string()),
+            function_names: vec![binary::NameAssoc {
+                index: 0,
+                name: "".to_string(),
+            }]
+            .into_boxed_slice(),
+        })
+    );
+}
+
+#[test]
+fn test_should_keep_valid_name_subsections_when_one_is_malformed() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, 0x0e, // custom section
+        0x04, b'n', b'a', b'm', b'e', // name
+        0x00, 0x02, 0x01, b'm', // module name: "m"
+        0x01, 0x03, 0x01, 0x00, 0x05, // function names: name length runs past the subsection
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result.name_section,
+        Some(binary::NameSection {
+            module_name: Some("m".to_string()),
+            function_names: Box::from([]),
+        })
+    );
+}
+
+#[test]
+fn test_should_ignore_name_section_with_invalid_utf8() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, 0x0b, // custom section
+        0x04, b'n', b'a', b'm', b'e', // name
+        0x01, 0x04, 0x01, 0x00, 0x01, 0xff, // function names: 0 => "\xff"
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(result.name_section, None);
+}
+
+#[test]
+fn test_should_keep_custom_section_bytes() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, 0x0f, // custom section
+        0x0b, b'.', b'd', b'e', b'b', b'u', b'g', b'_', b'l', b'i', b'n', b'e', // name
+        0x01, 0x02, 0x03, // bytes
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode().unwrap();
+
+    assert_eq!(
+        result.custom_sections,
+        vec![binary::CustomSection {
+            name: ".debug_line".to_string(),
+            bytes: Box::from([0x01, 0x02, 0x03]),
+        }]
+    );
+}
+
+#[test]
+fn test_should_returns_error_when_custom_section_name_exceeds_section_size() {
+    let wasm = [
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, 0x03, // custom section
+        0x05, b'a', b'b', // name length runs past the section
+    ];
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let result = decoder.decode();
+
+    assert_eq!(result.err(), Some(Error::UnexpectedEof));
+}
+
 #[test]
 fn test_should_decode_function_section() {
     let wasm = wat2wasm(
@@ -0,0 +1,120 @@
+use decoder::{Decoder, Error, LineTable, Location};
+
+/// Builds a DWARF 4 line program for `src/main.rs` with rows at offsets 5 (line 42, column 5)
+/// and 9 (line 43), ending at offset 12.
+fn debug_line() -> Vec<u8> {
+    let mut header = vec![
+        0x01, // minimum_instruction_length
+        0x01, // maximum_operations_per_instruction
+        0x01, // default_is_stmt
+        0xfb, // line_base: -5
+        0x0e, // line_range
+        0x0d, // opcode_base
+        // standard_opcode_lengths
+        0x00, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01,
+    ];
+    header.extend(b"src\0\0"); // include_directories
+    header.extend(b"main.rs\0\x01\x00\x00\0"); // file_names
+
+    let program = [
+        0x00, 0x05, 0x02, 0x05, 0x00, 0x00, 0x00, // DW_LNE_set_address 5
+        0x03, 0x29, // DW_LNS_advance_line 41
+        0x05, 0x05, // DW_LNS_set_column 5
+        0x01, // DW_LNS_copy
+        0x02, 0x04, // DW_LNS_advance_pc 4
+        0x03, 0x01, // DW_LNS_advance_line 1
+        0x05, 0x00, // DW_LNS_set_column 0
+        0x01, // DW_LNS_copy
+        0x02, 0x03, // DW_LNS_advance_pc 3
+        0x00, 0x01, 0x01, // DW_LNE_end_sequence
+    ];
+
+    let mut unit = vec![0x04, 0x00]; // version
+    unit.extend((header.len() as u32).to_le_bytes());
+    unit.extend(header);
+    unit.extend(program);
+
+    let mut debug_line = (unit.len() as u32).to_le_bytes().to_vec();
+    debug_line.extend(unit);
+    debug_line
+}
+
+fn module_with_custom_section(name: &str, bytes: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    leb128::write::unsigned(&mut payload, name.len() as u64).unwrap();
+    payload.extend(name.as_bytes());
+    payload.extend(bytes);
+
+    let mut wasm = vec![
+        0x00, 0x61, 0x73, 0x6d, // magic
+        0x01, 0x00, 0x00, 0x00, // version
+        0x00, // custom section
+    ];
+    leb128::write::unsigned(&mut wasm, payload.len() as u64).unwrap();
+    wasm.extend(payload);
+    wasm
+}
+
+#[test]
+fn test_should_map_code_offsets_to_source_locations() {
+    let wasm = module_with_custom_section(".debug_line", &debug_line());
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let module = decoder.decode().unwrap();
+    let line_table = LineTable::from_module(&module).unwrap();
+
+    let main_42 = Location {
+        file: "src/main.rs".to_string(),
+        line: 42,
+        column: 5,
+    };
+    let main_43 = Location {
+        file: "src/main.rs".to_string(),
+        line: 43,
+        column: 0,
+    };
+
+    assert_eq!(line_table.lookup(4), None);
+    assert_eq!(line_table.lookup(5), Some(&main_42));
+    assert_eq!(line_table.lookup(8), Some(&main_42));
+    assert_eq!(line_table.lookup(9), Some(&main_43));
+    assert_eq!(line_table.lookup(11), Some(&main_43));
+    assert_eq!(line_table.lookup(12), None);
+}
+
+#[test]
+fn test_should_format_location_like_a_source_position() {
+    let location = Location {
+        file: "src/main.rs".to_string(),
+        line: 42,
+        column: 5,
+    };
+    assert_eq!(location.to_string(), "src/main.rs:42:5");
+
+    let location = Location {
+        column: 0,
+        ..location
+    };
+    assert_eq!(location.to_string(), "src/main.rs:42");
+}
+
+#[test]
+fn test_should_return_empty_line_table_without_debug_line() {
+    let wasm = module_with_custom_section(".debug_info", &[0x00]);
+
+    let mut decoder = Decoder::new(&wasm[..]);
+    let module = decoder.decode().unwrap();
+    let line_table = LineTable::from_module(&module).unwrap();
+
+    assert_eq!(line_table, LineTable::default());
+    assert_eq!(line_table.lookup(0), None);
+}
+
+#[test]
+fn test_should_returns_error_when_debug_line_is_truncated() {
+    let debug_line = debug_line();
+
+    let result = LineTable::parse(&debug_line[..debug_line.len() - 4], &[], &[]);
+
+    assert_eq!(result.err(), Some(Error::InvalidDwarf));
+}
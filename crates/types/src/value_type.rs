@@ -1,3 +1,5 @@
+use crate::ref_type::RefType;
+
 /// Value type.
 ///
 /// WebAssembly specification: https://webassembly.github.io/spec/core/syntax/types.html#value-types
@@ -8,8 +10,10 @@ pub enum ValueType {
     I64, // 0x7E
     F32, // 0x7D
     F64, // 0x7C
-         // TODO: add vectype
-         // TODO: add reftype
+    // Vector types.
+    V128, // 0x7B
+    // Reference types.
+    Ref(RefType), // 0x70, 0x6F
 }
 
 impl From<u8> for ValueType {
@@ -19,6 +23,9 @@ impl From<u8> for ValueType {
             0x7E => ValueType::I64,
             0x7D => ValueType::F32,
             0x7C => ValueType::F64,
+            0x7B => ValueType::V128,
+            0x70 => ValueType::Ref(RefType::FuncRef),
+            0x6F => ValueType::Ref(RefType::ExternRef),
             _ => panic!("Invalid value type: {}", value),
         }
     }